@@ -6,17 +6,20 @@ use discord_rich_presence::{
     DiscordIpc, DiscordIpcClient,
 };
 use log::{debug, info, warn};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use simplelog::*;
 use std::{
+    collections::HashMap,
     ffi::OsStr,
     fs,
     io::ErrorKind,
     path::PathBuf,
+    sync::{Mutex, OnceLock},
     thread::sleep,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
-use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, RefreshKind, System};
+use sysinfo::{Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshKind, System};
 
 pub fn log_init(log_level: LevelFilter) -> () {
     TermLogger::init(
@@ -33,6 +36,9 @@ pub fn log_init(log_level: LevelFilter) -> () {
     .expect("logger should not be set more than once");
 }
 
+/// Name of the profile used when the user doesn't pass `--profile` or `--config`.
+const DEFAULT_PROFILE: &str = "default";
+
 fn config_path() -> PathBuf {
     match config_dir() {
         Some(dir) => dir.join("carp"),
@@ -40,22 +46,89 @@ fn config_path() -> PathBuf {
     }
 }
 
-fn config_file() -> PathBuf {
+fn profiles_dir() -> PathBuf {
+    config_path().join("profiles")
+}
+
+fn profile_file(profile: &str) -> PathBuf {
+    profiles_dir().join(format!("{profile}.json"))
+}
+
+/// Where the single config file used to live, before named profiles existed.
+fn legacy_config_file() -> PathBuf {
     config_path().join("targets.json")
 }
 
-pub fn write_config(config: &Config) -> Result<()> {
+/// One-time migration for users upgrading from the pre-profiles layout: if the `default`
+/// profile hasn't been created yet but a legacy `carp/targets.json` exists, move it into place
+/// so existing single-config users keep their client ID and targets.
+fn migrate_legacy_config(source: &ConfigSource) -> Result<()> {
+    let ConfigSource::Profile(name) = source else {
+        return Ok(());
+    };
+    if name != DEFAULT_PROFILE {
+        return Ok(());
+    }
+
+    let new_path = profile_file(name);
+    let old_path = legacy_config_file();
+
+    if new_path.exists() || !old_path.exists() {
+        return Ok(());
+    }
+
+    info!(
+        "Migrating legacy config at {} to the '{}' profile",
+        old_path.display(),
+        DEFAULT_PROFILE
+    );
+
+    if let Some(parent) = new_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if fs::rename(&old_path, &new_path).is_err() {
+        fs::copy(&old_path, &new_path)?;
+        fs::remove_file(&old_path)?;
+    }
+
+    Ok(())
+}
+
+/// Where a `Config` is read from and written to: either a named profile in the profiles
+/// directory, or an arbitrary file passed via `--config`.
+#[derive(Debug, Clone)]
+enum ConfigSource {
+    Profile(String),
+    File(PathBuf),
+}
+
+impl ConfigSource {
+    fn path(&self) -> PathBuf {
+        match self {
+            ConfigSource::Profile(name) => profile_file(name),
+            ConfigSource::File(path) => path.clone(),
+        }
+    }
+}
+
+fn write_config(config: &Config, source: &ConfigSource) -> Result<()> {
     let ser_config = serde_json::to_string(&config)?;
+    let path = source.path();
 
-    if !config_path().exists() {
-        fs::create_dir_all(config_path())?;
+    if let Some(parent) = path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent)?;
+        }
     }
 
-    Ok(fs::write(config_file(), ser_config)?)
+    Ok(fs::write(path, ser_config)?)
 }
 
-pub fn get_config() -> Result<Config> {
-    let config_file = match fs::read(&config_file()) {
+fn get_config(source: &ConfigSource) -> Result<Config> {
+    migrate_legacy_config(source)?;
+
+    let config_file = match fs::read(source.path()) {
         Err(err) => {
             if err.kind() == ErrorKind::NotFound {
                 warn!("Failed to read config file: {}", err);
@@ -92,10 +165,27 @@ fn list_config(config: &Config, mut compact: bool, detailed: bool) {
     }
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Config {
     pub client_id: u64,
     pub targets: Vec<Target>,
+    /// How often, in seconds, to scan running processes for a match.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            client_id: 0,
+            targets: Vec::new(),
+            poll_interval_secs: default_poll_interval_secs(),
+        }
+    }
+}
+
+fn default_poll_interval_secs() -> u64 {
+    1
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -103,6 +193,177 @@ pub struct Target {
     pub process_name: String,
     pub display_name: String,
     pub image: String,
+    /// Additional conditions that must all pass, alongside matching `process_name`, for this
+    /// target to be considered active. Empty for targets that only care about the process name.
+    #[serde(default)]
+    pub matchers: Vec<Matcher>,
+}
+
+impl Target {
+    /// The full matcher chain for this target: an implicit exact-name match on `process_name`
+    /// followed by whatever extra matchers have been configured.
+    fn matcher_chain(&self) -> Vec<Matcher> {
+        let mut chain = vec![Matcher::Name(self.process_name.clone())];
+        chain.extend(self.matchers.iter().cloned());
+        chain
+    }
+
+    /// Whether `proc` satisfies every matcher in this target's chain.
+    fn is_match(&self, proc: &Process) -> bool {
+        self.matcher_chain().iter().all(|matcher| matcher.matches(proc))
+    }
+}
+
+/// A single condition that a process must satisfy. A [`Target`] is active when all of its
+/// matchers pass for some running process.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Matcher {
+    /// Exact process name match.
+    Name(String),
+    /// Process name matches a regular expression.
+    NameRegex(String),
+    /// The process command line contains this substring.
+    CmdlineContains(String),
+    /// CPU usage is above this percentage. Requires a `cpu`-enabled refresh; always false on the
+    /// first refresh since there is no prior sample to compute a delta from.
+    CpuAbove(f32),
+    /// Resident memory usage, in bytes, is above this value.
+    MemoryAbove(u64),
+    /// The process is in this status.
+    Status(MatcherStatus),
+}
+
+/// A trimmed-down mirror of [`sysinfo::ProcessStatus`] covering the statuses a matcher can check.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum MatcherStatus {
+    Running,
+    Sleeping,
+    Idle,
+}
+
+impl MatcherStatus {
+    fn matches(self, status: ProcessStatus) -> bool {
+        matches!(
+            (self, status),
+            (MatcherStatus::Running, ProcessStatus::Run)
+                | (MatcherStatus::Sleeping, ProcessStatus::Sleep)
+                | (MatcherStatus::Idle, ProcessStatus::Idle)
+        )
+    }
+}
+
+/// Process-wide cache of compiled `NameRegex` patterns, so a matcher checked against every
+/// running process on every poll tick doesn't recompile its regex each time.
+fn regex_cache() -> &'static Mutex<HashMap<String, Regex>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Compiles `pattern`, or returns the already-compiled regex from the cache.
+fn compiled_regex(pattern: &str) -> Option<Regex> {
+    let mut cache = regex_cache().lock().unwrap();
+    if let Some(regex) = cache.get(pattern) {
+        return Some(regex.clone());
+    }
+
+    let regex = Regex::new(pattern).ok()?;
+    cache.insert(pattern.to_string(), regex.clone());
+    Some(regex)
+}
+
+/// Returns an error if `pattern` isn't a valid regex, so bad patterns are caught at
+/// `config add`/`config edit` time instead of silently never matching at runtime.
+fn validate_regex(pattern: &str) -> Result<()> {
+    Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|err| anyhow!("'{pattern}' is not a valid regex: {err}"))
+}
+
+/// Evaluates whether a process satisfies a single condition.
+pub trait StateMatcher {
+    fn matches(&self, proc: &Process) -> bool;
+}
+
+impl StateMatcher for Matcher {
+    fn matches(&self, proc: &Process) -> bool {
+        match self {
+            Matcher::Name(name) => proc.name() == OsStr::new(name),
+            Matcher::NameRegex(pattern) => compiled_regex(pattern)
+                .map(|re| re.is_match(&proc.name().to_string_lossy()))
+                .unwrap_or(false),
+            Matcher::CmdlineContains(substring) => proc
+                .cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .contains(substring.as_str()),
+            Matcher::CpuAbove(threshold) => proc.cpu_usage() > *threshold,
+            Matcher::MemoryAbove(threshold) => proc.memory() > *threshold,
+            Matcher::Status(status) => status.matches(proc.status()),
+        }
+    }
+}
+
+/// Expands `{cpu}`, `{mem}`, `{pid}`, `{uptime}`, and `{name}` placeholders in `template` with
+/// live values read from `proc`.
+fn render_template(template: &str, proc: &Process) -> String {
+    let mut rendered = template.to_string();
+
+    if rendered.contains("{cpu}") {
+        rendered = rendered.replace("{cpu}", &format!("{:.1}%", proc.cpu_usage()));
+    }
+    if rendered.contains("{mem}") {
+        rendered = rendered.replace("{mem}", &format_bytes(proc.memory()));
+    }
+    if rendered.contains("{pid}") {
+        rendered = rendered.replace("{pid}", &proc.pid().to_string());
+    }
+    if rendered.contains("{uptime}") {
+        rendered = rendered.replace("{uptime}", &format_uptime(proc.start_time()));
+    }
+    if rendered.contains("{name}") {
+        rendered = rendered.replace("{name}", &proc.name().to_string_lossy());
+    }
+
+    rendered
+}
+
+/// Formats a byte count as a human-readable size, e.g. `512.0 MB`.
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Formats the time elapsed since a process's Unix `start_time` as e.g. `1h 4m 9s`.
+fn format_uptime(start_time: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+    let elapsed = now.saturating_sub(start_time);
+
+    let hours = elapsed / 3600;
+    let minutes = (elapsed % 3600) / 60;
+    let seconds = elapsed % 60;
+
+    if hours > 0 {
+        format!("{hours}h {minutes}m {seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
 }
 
 /// Returns the index of a process in the targets list or returns an error message if no process is found.
@@ -125,10 +386,29 @@ fn add_process(config: &mut Config, new_target: CliConfigAdd) -> Result<()> {
         return Err(anyhow!("That process already exists in the target list"));
     }
 
+    let mut matchers = Vec::new();
+    if let Some(pattern) = new_target.name_regex {
+        validate_regex(&pattern)?;
+        matchers.push(Matcher::NameRegex(pattern));
+    }
+    if let Some(substring) = new_target.cmd_contains {
+        matchers.push(Matcher::CmdlineContains(substring));
+    }
+    if let Some(threshold) = new_target.cpu_above {
+        matchers.push(Matcher::CpuAbove(threshold));
+    }
+    if let Some(threshold) = new_target.mem_above {
+        matchers.push(Matcher::MemoryAbove(threshold));
+    }
+    if let Some(status) = new_target.status {
+        matchers.push(Matcher::Status(status));
+    }
+
     let target = Target {
         process_name: new_target.process,
         display_name: new_target.display,
         image: new_target.image,
+        matchers,
     };
 
     if let Some(index) = new_target.index {
@@ -150,6 +430,27 @@ fn remove_process(config: &mut Config, process: String) -> Result<()> {
     Ok(())
 }
 
+/// Computes the destination index for moving the target at `index` to immediately before/after
+/// the target at `reference_index`, accounting for the shift caused by removing the moved
+/// element before reinserting it.
+fn relative_index(index: usize, reference_index: usize, after: bool) -> Result<usize> {
+    if reference_index == index {
+        return Err(anyhow!("Cannot reorder a process relative to itself"));
+    }
+
+    let shifted_reference = if reference_index > index {
+        reference_index - 1
+    } else {
+        reference_index
+    };
+
+    Ok(if after {
+        shifted_reference + 1
+    } else {
+        shifted_reference
+    })
+}
+
 fn move_process(
     config: &mut Config,
     process: String,
@@ -164,6 +465,12 @@ fn move_process(
         ConfigReorderOperation::Set(target_index) => {
             (target_index as usize).clamp(0, config.targets.len() - 1)
         }
+        ConfigReorderOperation::Before(reference) => {
+            relative_index(index, get_process_index(&config.targets, reference)?, false)?
+        }
+        ConfigReorderOperation::After(reference) => {
+            relative_index(index, get_process_index(&config.targets, reference)?, true)?
+        }
     };
 
     if new_index == index {
@@ -200,17 +507,99 @@ fn edit_process(config: &mut Config, process: String, edits: CliConfigEdit) -> R
         config.targets[index].image = image;
     }
 
+    if let Some(pattern) = edits.name_regex {
+        validate_regex(&pattern)?;
+        config.targets[index].matchers.push(Matcher::NameRegex(pattern));
+    }
+    if let Some(substring) = edits.cmd_contains {
+        config.targets[index]
+            .matchers
+            .push(Matcher::CmdlineContains(substring));
+    }
+    if let Some(threshold) = edits.cpu_above {
+        config.targets[index].matchers.push(Matcher::CpuAbove(threshold));
+    }
+    if let Some(threshold) = edits.mem_above {
+        config.targets[index]
+            .matchers
+            .push(Matcher::MemoryAbove(threshold));
+    }
+    if let Some(status) = edits.status {
+        config.targets[index].matchers.push(Matcher::Status(status));
+    }
+
+    Ok(())
+}
+
+fn create_profile(name: &str) -> Result<()> {
+    let path = profile_file(name);
+    if path.exists() {
+        return Err(anyhow!("A profile named '{name}' already exists"));
+    }
+
+    write_config(&Config::default(), &ConfigSource::Profile(name.to_string()))
+}
+
+fn list_profiles() {
+    let entries = match fs::read_dir(profiles_dir()) {
+        Ok(entries) => entries,
+        Err(_) => {
+            println!("No profiles found");
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension() != Some(OsStr::new("json")) {
+            continue;
+        }
+
+        if let Some(name) = path.file_stem().and_then(OsStr::to_str) {
+            println!("{name}");
+        }
+    }
+}
+
+fn copy_profile(from: &str, to: &str) -> Result<()> {
+    let from_path = profile_file(from);
+    if !from_path.exists() {
+        return Err(anyhow!("No profile named '{from}' exists"));
+    }
+
+    let to_path = profile_file(to);
+    if to_path.exists() {
+        return Err(anyhow!("A profile named '{to}' already exists"));
+    }
+
+    if !profiles_dir().exists() {
+        fs::create_dir_all(profiles_dir())?;
+    }
+
+    fs::copy(from_path, to_path)?;
     Ok(())
 }
 
+fn delete_profile(name: &str) -> Result<()> {
+    let path = profile_file(name);
+    if !path.exists() {
+        return Err(anyhow!("No profile named '{name}' exists"));
+    }
+
+    Ok(fs::remove_file(path)?)
+}
+
 fn main() -> Result<()> {
     log_init(LevelFilter::Debug);
 
     if std::env::args_os().len() <= 1 {
-        app_loop(get_config()?);
+        app_loop(get_config(&ConfigSource::Profile(
+            DEFAULT_PROFILE.to_string(),
+        ))?);
     }
     let cli = Cli::parse();
-    let mut config = get_config()?;
+    let source = cli.config_source();
+    let mut config = get_config(&source)?;
 
     match cli.subcommands {
         CliSubcommands::Run => app_loop(config),
@@ -218,6 +607,7 @@ fn main() -> Result<()> {
             CliConfig::Add(new_target) => add_process(&mut config, new_target),
             CliConfig::Edit { process, flags } => edit_process(&mut config, process, flags),
             CliConfig::Id { client_id } => Ok(config.client_id = client_id),
+            CliConfig::PollInterval { secs } => Ok(config.poll_interval_secs = secs),
             CliConfig::List {
                 force_compact,
                 force_detailed,
@@ -226,10 +616,43 @@ fn main() -> Result<()> {
             CliConfig::Reorder { process, flags } => {
                 move_process(&mut config, process, flags.into())
             }
+            CliConfig::Profile { subcommands } => {
+                return match subcommands {
+                    CliConfigProfile::Create { name } => create_profile(&name),
+                    CliConfigProfile::List => {
+                        list_profiles();
+                        Ok(())
+                    }
+                    CliConfigProfile::Copy { from, to } => copy_profile(&from, &to),
+                    CliConfigProfile::Delete { name } => delete_profile(&name),
+                }
+            }
         }?,
     }
 
-    write_config(&config)
+    write_config(&config, &source)
+}
+
+/// Retries `client.connect()` with exponential backoff, starting at 1 second and capping at 30
+/// seconds, until it succeeds. Called whenever an IPC call fails, since that's how this crate
+/// finds out the Discord client was restarted or dropped the pipe.
+fn reconnect_with_backoff(client: &mut DiscordIpcClient) {
+    let mut backoff = Duration::from_secs(1);
+
+    loop {
+        sleep(backoff);
+
+        match client.connect() {
+            Ok(()) => {
+                info!("Reconnected to Discord");
+                return;
+            }
+            Err(err) => {
+                warn!("Still failed to reconnect to Discord: {}", err);
+                backoff = (backoff * 2).min(Duration::from_secs(30));
+            }
+        }
+    }
 }
 
 fn app_loop(config: Config) -> ! {
@@ -244,44 +667,74 @@ fn app_loop(config: Config) -> ! {
     let mut client = DiscordIpcClient::new(&config.client_id.to_string()).unwrap();
 
     debug!("Attempting to connect to Discord...");
-    client.connect().unwrap();
+    if let Err(err) = client.connect() {
+        warn!("Failed to connect to Discord: {}", err);
+        reconnect_with_backoff(&mut client);
+    }
     debug!("Connected");
 
     let mut last_detected_process = "";
+    let mut last_rendered_text = String::new();
+    // CPU usage is a delta between two samples, so `CpuAbove` matchers never pass on the first refresh
+    let mut cpu_sampled = false;
     loop {
-        processes.refresh_processes_specifics(ProcessesToUpdate::All, ProcessRefreshKind::new());
+        processes.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            ProcessRefreshKind::new().with_cpu().with_memory(),
+        );
+        let snapshot: Vec<&Process> = processes.processes().values().collect();
+
         for (index, target) in config.targets.iter().enumerate() {
-            if let None = processes
-                .processes_by_exact_name(OsStr::new(&target.process_name))
-                .next()
-            {
+            let cpu_pending = !cpu_sampled
+                && target
+                    .matchers
+                    .iter()
+                    .any(|matcher| matches!(matcher, Matcher::CpuAbove(_)));
+            let Some(proc) = snapshot
+                .iter()
+                .find(|proc| !cpu_pending && target.is_match(proc))
+            else {
                 if index == config.targets.len() - 1 && last_detected_process != "None" {
-                    client.clear_activity().unwrap();
+                    if let Err(err) = client.clear_activity() {
+                        warn!("Lost connection to Discord while clearing activity: {}", err);
+                        reconnect_with_backoff(&mut client);
+                    }
                     last_detected_process = "None";
+                    last_rendered_text.clear();
                     info!("No process detected");
                 }
                 continue;
+            };
+            let proc = *proc;
+
+            let target_changed = last_detected_process != target.process_name;
+            if target_changed {
+                last_detected_process = &target.process_name;
+                info!("New process detected: {}", target.process_name);
             }
 
-            if last_detected_process == target.process_name {
+            let rendered_text = render_template(&target.display_name, proc);
+            if !target_changed && rendered_text == last_rendered_text {
                 break;
             }
 
-            last_detected_process = &target.process_name;
-            info!("New process detected: {}", target.process_name);
-
             let mut details = String::new();
             let mut state = String::new();
 
             // This disgusting block of if statements just splits display names above 35 characters into
             // 2 lines (details & state) to hopefully mitigate any ellipses on the first line
-            if target.display_name.chars().count() > 35 {
-                let words: Vec<&str> = target.display_name.split_whitespace().collect();
+            if rendered_text.chars().count() > 35 {
+                let words: Vec<&str> = rendered_text.split_whitespace().collect();
 
                 // blocks of text or exceedingly long words can just be forcefully split
                 if words.len() <= 1 || words[0].chars().count() > 35 {
-                    details = target.display_name.clone();
-                    state = details.split_off(35);
+                    details = rendered_text.clone();
+                    let byte_index = details
+                        .char_indices()
+                        .nth(35)
+                        .map(|(index, _)| index)
+                        .unwrap_or(details.len());
+                    state = details.split_off(byte_index);
                 } else {
                     for (index, word) in words.iter().enumerate() {
                         // basically add words until it goes over 36 characters (36 instead of 35 to compensate for the
@@ -298,7 +751,7 @@ fn app_loop(config: Config) -> ! {
                     }
                 }
             } else {
-                details = target.display_name.clone();
+                details = rendered_text.clone();
             }
 
             let start_time = SystemTime::now()
@@ -315,13 +768,20 @@ fn app_loop(config: Config) -> ! {
                 activity = activity.state(state.trim());
             }
 
-            client.set_activity(activity).unwrap();
+            match client.set_activity(activity) {
+                Ok(()) => last_rendered_text = rendered_text,
+                Err(err) => {
+                    warn!("Lost connection to Discord while setting activity: {}", err);
+                    reconnect_with_backoff(&mut client);
+                }
+            }
 
             break;
         }
+        cpu_sampled = true;
 
         // Prevents Discord from forcefully closing the connection
-        sleep(Duration::from_secs(1));
+        sleep(Duration::from_secs(config.poll_interval_secs));
     }
 }
 
@@ -331,10 +791,36 @@ fn app_loop(config: Config) -> ! {
 	env!("CARGO_PKG_VERSION")
 ))]
 struct Cli {
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "config",
+        help = "The named profile to use"
+    )]
+    profile: Option<String>,
+    #[arg(
+        long,
+        global = true,
+        help = "Load and save targets from this file instead of a profile"
+    )]
+    config: Option<PathBuf>,
     #[command(subcommand)]
     subcommands: CliSubcommands,
 }
 
+impl Cli {
+    fn config_source(&self) -> ConfigSource {
+        match &self.config {
+            Some(path) => ConfigSource::File(path.clone()),
+            None => ConfigSource::Profile(
+                self.profile
+                    .clone()
+                    .unwrap_or_else(|| DEFAULT_PROFILE.to_string()),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Subcommand)]
 enum CliSubcommands {
     #[command(about = "Run the program")]
@@ -362,6 +848,11 @@ enum CliConfig {
         #[arg(help = "The ID of your Discord client")]
         client_id: u64,
     },
+    #[command(about = "Set how often running processes are scanned")]
+    PollInterval {
+        #[arg(help = "How often to scan running processes, in seconds")]
+        secs: u64,
+    },
     #[command(about = "List the config")]
     #[group(multiple = false)]
     List {
@@ -382,6 +873,34 @@ enum CliConfig {
         #[arg(help = "The process name (not necessarily the executable name)")]
         process: String,
     },
+    #[command(about = "Manage named config profiles")]
+    Profile {
+        #[command(subcommand)]
+        subcommands: CliConfigProfile,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CliConfigProfile {
+    #[command(about = "Create a new, empty profile")]
+    Create {
+        #[arg(help = "The name of the new profile")]
+        name: String,
+    },
+    #[command(about = "List all profiles")]
+    List,
+    #[command(about = "Copy a profile to a new name")]
+    Copy {
+        #[arg(help = "The profile to copy")]
+        from: String,
+        #[arg(help = "The name of the new profile")]
+        to: String,
+    },
+    #[command(about = "Delete a profile")]
+    Delete {
+        #[arg(help = "The name of the profile to delete")]
+        name: String,
+    },
 }
 
 #[derive(Debug, Args)]
@@ -404,6 +923,22 @@ struct CliConfigAdd {
         help = "The process name (not necessarily the executable name)"
     )]
     process: String,
+    #[arg(
+        long,
+        help = "Only match when the process name matches this regex, in addition to the exact name"
+    )]
+    name_regex: Option<String>,
+    #[arg(long, help = "Only match when the process command line contains this substring")]
+    cmd_contains: Option<String>,
+    #[arg(long, help = "Only match when the process's CPU usage is above this percentage")]
+    cpu_above: Option<f32>,
+    #[arg(
+        long,
+        help = "Only match when the process's resident memory usage is above this many bytes"
+    )]
+    mem_above: Option<u64>,
+    #[arg(long, value_enum, help = "Only match when the process is in this status")]
+    status: Option<MatcherStatus>,
 }
 
 #[derive(Debug, Args)]
@@ -415,6 +950,16 @@ struct CliConfigEdit {
     display: Option<String>,
     #[arg(short = 'i', long, help = "The new image URL/key")]
     image: Option<String>,
+    #[arg(long, help = "Append a matcher requiring the process name to match this regex")]
+    name_regex: Option<String>,
+    #[arg(long, help = "Append a matcher requiring the process command line to contain this substring")]
+    cmd_contains: Option<String>,
+    #[arg(long, help = "Append a matcher requiring CPU usage above this percentage")]
+    cpu_above: Option<f32>,
+    #[arg(long, help = "Append a matcher requiring resident memory usage above this many bytes")]
+    mem_above: Option<u64>,
+    #[arg(long, value_enum, help = "Append a matcher requiring this process status")]
+    status: Option<MatcherStatus>,
 }
 
 #[derive(Debug, Args)]
@@ -430,12 +975,26 @@ struct CliConfigReorder {
         help = "Set the priority of the process to a specific index. Highest priority is 0"
     )]
     set: Option<u32>,
+    #[arg(
+        short = 'b',
+        long,
+        help = "Move the process so it is immediately before this process"
+    )]
+    before: Option<String>,
+    #[arg(
+        short = 'a',
+        long,
+        help = "Move the process so it is immediately after this process"
+    )]
+    after: Option<String>,
 }
 
 enum ConfigReorderOperation {
     Increase,
     Decrease,
     Set(u32),
+    Before(String),
+    After(String),
 }
 
 impl From<CliConfigReorder> for ConfigReorderOperation {
@@ -445,17 +1004,37 @@ impl From<CliConfigReorder> for ConfigReorderOperation {
                 increase: true,
                 decrease: false,
                 set: None,
+                before: None,
+                after: None,
             } => ConfigReorderOperation::Increase,
             CliConfigReorder {
                 increase: false,
                 decrease: true,
                 set: None,
+                before: None,
+                after: None,
             } => ConfigReorderOperation::Decrease,
             CliConfigReorder {
                 increase: false,
                 decrease: false,
                 set: Some(index),
+                before: None,
+                after: None,
             } => ConfigReorderOperation::Set(index),
+            CliConfigReorder {
+                increase: false,
+                decrease: false,
+                set: None,
+                before: Some(reference),
+                after: None,
+            } => ConfigReorderOperation::Before(reference),
+            CliConfigReorder {
+                increase: false,
+                decrease: false,
+                set: None,
+                before: None,
+                after: Some(reference),
+            } => ConfigReorderOperation::After(reference),
             _ => unreachable!("Only one operation flag should ever be active"),
         }
     }